@@ -1,4 +1,4 @@
-pub mod recursive;
+pub mod cyclic;
 mod types;
 
 pub use types::*;
@@ -9,6 +9,8 @@ pub enum Type {
     Literal(LiteralType),
     Union(UnionType),
     Intersection(IntersectionType),
+    Array(ArrayType),
+    Tuple(TupleType),
 }
 
 impl fmt::Display for Type {
@@ -20,6 +22,8 @@ impl fmt::Display for Type {
             Type::Literal(lit) => fmt::Display::fmt(lit, f),
             Type::Union(u) => fmt::Display::fmt(u, f),
             Type::Intersection(i) => fmt::Display::fmt(i, f),
+            Type::Array(a) => fmt::Display::fmt(a, f),
+            Type::Tuple(t) => fmt::Display::fmt(t, f),
         }
     }
 }
@@ -27,6 +31,15 @@ impl fmt::Display for Type {
 impl Type {
     /// Checks that `rhs` can be assigned to `self`.
     pub fn can_assign(&self, rhs: &Self) -> bool {
+        // Intersections are compared in their normalized form, e.g.
+        // `(number | string) & (number | nil)` is compared as `number`.
+        if let Some(lhs) = self.normalize_intersection() {
+            return lhs.can_assign(rhs);
+        }
+        if let Some(rhs) = rhs.normalize_intersection() {
+            return self.can_assign(&rhs);
+        }
+
         match (self, rhs) {
             // Nothing can be assigned to the never type except the never type
             // itself.
@@ -40,14 +53,121 @@ impl Type {
             (Type::Literal(lhs), Type::Literal(rhs)) => lhs.can_assign(rhs),
             // Literal can be assigned to primitive of same type.
             (Type::Primitive(lhs), Type::Literal(rhs)) => *lhs == rhs.primitive,
+            // Arrays are covariant in their element type.
+            (Type::Array(lhs), Type::Array(rhs)) => lhs.element.can_assign(&rhs.element),
+            // Tuple can be assigned to array if all its elements can.
+            (Type::Array(lhs), Type::Tuple(rhs)) => {
+                rhs.elements.iter().all(|e| lhs.element.can_assign(e))
+            }
+            // Tuple can be assigned if elements can be assigned positionally.
+            (Type::Tuple(lhs), Type::Tuple(rhs)) => lhs.can_assign(rhs),
             // Union.
             (Type::Union(lhs), rhs) => lhs.can_assign(rhs),
             // Intersection.
             (Type::Intersection(lhs), rhs) => lhs.can_assign(rhs),
+            // Intersection can be assigned if one of its variants can.
+            (lhs, Type::Intersection(rhs)) => rhs.variants.iter().any(|v| lhs.can_assign(v)),
             // Anything else is false.
             _ => false,
         }
     }
+
+    /// Returns type of `self < rhs` expression or [None] if operands can't be
+    /// compared.
+    pub fn lt(&self, rhs: &Self) -> Option<Type> {
+        self.normalize()
+            .can_order(&rhs.normalize())
+            .then_some(Type::Primitive(PrimitiveType::Boolean))
+    }
+
+    /// Returns type of `self <= rhs` expression or [None] if operands can't be
+    /// compared.
+    pub fn le(&self, rhs: &Self) -> Option<Type> {
+        self.normalize()
+            .can_order(&rhs.normalize())
+            .then_some(Type::Primitive(PrimitiveType::Boolean))
+    }
+
+    /// Returns type of `self == rhs` expression or [None] if operands have no
+    /// value in common, as the comparison would always be false. Comparisons
+    /// involving `never` are rejected as no value has this type.
+    pub fn eq(&self, rhs: &Self) -> Option<Type> {
+        match intersect(self.normalize(), rhs.normalize()) {
+            Type::Never(_) => None,
+            _ => Some(Type::Primitive(PrimitiveType::Boolean)),
+        }
+    }
+
+    /// Checks that `self` and `rhs` can be compared using `<` and `<=`
+    /// operators. Only numbers and strings are ordered in Lua and, as for
+    /// equality, `never` can't be compared. Both types must be normalized.
+    fn can_order(&self, rhs: &Self) -> bool {
+        match (self, rhs) {
+            (Type::Never(_), _) | (_, Type::Never(_)) => false,
+            // All union's variants must be comparable.
+            (Type::Union(lhs), rhs) => lhs.variants.iter().all(|v| v.can_order(rhs)),
+            (lhs, Type::Union(rhs)) => rhs.variants.iter().all(|v| lhs.can_order(v)),
+            // A single intersection's variant being comparable is enough.
+            (Type::Intersection(lhs), rhs) => lhs.variants.iter().any(|v| v.can_order(rhs)),
+            (lhs, Type::Intersection(rhs)) => rhs.variants.iter().any(|v| lhs.can_order(v)),
+            (Type::Any(_), Type::Any(_)) => true,
+            // Any may only be compared to numbers and strings.
+            (Type::Any(_), t) | (t, Type::Any(_)) => matches!(
+                t,
+                Type::Primitive(PrimitiveType::Number | PrimitiveType::String)
+                    | Type::Literal(LiteralType {
+                        primitive: PrimitiveType::Number | PrimitiveType::String,
+                        ..
+                    })
+            ),
+            (
+                Type::Primitive(lhs) | Type::Literal(LiteralType { primitive: lhs, .. }),
+                Type::Primitive(rhs) | Type::Literal(LiteralType { primitive: rhs, .. }),
+            ) => lhs == rhs && matches!(lhs, PrimitiveType::Number | PrimitiveType::String),
+            _ => false,
+        }
+    }
+
+    /// Returns a simplified but equivalent type. Nested unions and
+    /// intersections are flattened, redundant variants are removed and
+    /// intersections are distributed over unions.
+    pub fn normalize(&self) -> Type {
+        match self {
+            Type::Union(u) => u.normalize(),
+            Type::Intersection(i) => i.normalize(),
+            Type::Array(a) => Type::Array(ArrayType::from(a.element.normalize())),
+            Type::Tuple(t) => Type::Tuple(TupleType::from(
+                t.elements.iter().map(Type::normalize).collect::<Vec<_>>(),
+            )),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns normalized type of an intersection if it isn't an intersection
+    /// anymore.
+    fn normalize_intersection(&self) -> Option<Type> {
+        match self {
+            Type::Intersection(i) => match i.normalize() {
+                Type::Intersection(_) => None,
+                t => Some(t),
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks that no value can be of both types. Only types of different Lua
+    /// types (e.g. `number` and `string`) or different literals are known to
+    /// be disjoint.
+    fn is_disjoint(&self, rhs: &Self) -> bool {
+        match (self, rhs) {
+            (Type::Primitive(_) | Type::Literal(_), Type::Primitive(_) | Type::Literal(_)) => {
+                !self.can_assign(rhs) && !rhs.can_assign(self)
+            }
+            (Type::Primitive(_) | Type::Literal(_), Type::Array(_) | Type::Tuple(_))
+            | (Type::Array(_) | Type::Tuple(_), Type::Primitive(_) | Type::Literal(_)) => true,
+            _ => false,
+        }
+    }
 }
 
 /// NeverType define the `never` type in our type system.
@@ -124,12 +244,121 @@ impl fmt::Display for LiteralType {
 
 impl LiteralType {
     fn can_assign(&self, rhs: &LiteralType) -> bool {
-        // TODO: fix lit comparison for float numbers as they're approximation
-        // of numbers.
-        self.lit == rhs.lit && self.primitive == rhs.primitive
+        if self.primitive != rhs.primitive {
+            return false;
+        }
+
+        match self.primitive {
+            // Number literals with different textual forms (e.g. `1`, `1.0`
+            // and `0x1`) can represent the same value.
+            PrimitiveType::Number => match (parse_number(&self.lit), parse_number(&rhs.lit)) {
+                (Some(lhs), Some(rhs)) => lhs == rhs,
+                _ => self.lit == rhs.lit,
+            },
+            _ => self.lit == rhs.lit,
+        }
     }
 }
 
+/// Parses a Lua number literal. [None] is returned for malformed literals and
+/// literals that don't fit in a finite f64.
+fn parse_number(lit: &str) -> Option<f64> {
+    let n = match lit.strip_prefix("0x").or_else(|| lit.strip_prefix("0X")) {
+        Some(hex) => parse_hex_number(hex)?,
+        None => parse_decimal_number(lit)?,
+    };
+
+    n.is_finite().then_some(n)
+}
+
+/// Parses a decimal number literal. Forms accepted by [str::parse] that aren't
+/// Lua literals, such as `inf`, `nan` or `+1`, are rejected.
+fn parse_decimal_number(lit: &str) -> Option<f64> {
+    let mantissa = match lit.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => {
+            parse_exponent(exp)?;
+            mantissa
+        }
+        None => lit,
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    if !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    lit.parse().ok()
+}
+
+/// Parses an hexadecimal number literal without its `0x` prefix. Literal may
+/// have a fractional part and a binary exponent (e.g. `1.8p4`). Digits are
+/// accumulated in an integer mantissa that is converted to f64 once.
+fn parse_hex_number(hex: &str) -> Option<f64> {
+    let (mantissa, mut exp) = match hex.split_once(['p', 'P']) {
+        Some((mantissa, exp)) => (mantissa, parse_exponent(exp)?),
+        None => (hex, 0),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+
+    let mut m: u128 = 0;
+    // Set if non-zero digits didn't fit in the mantissa.
+    let mut sticky = false;
+    let digits = int.chars().map(|c| (c, false));
+    for (c, is_frac) in digits.chain(frac.chars().map(|c| (c, true))) {
+        let d = c.to_digit(16)?;
+        if m >> 124 == 0 {
+            m = m << 4 | d as u128;
+            if is_frac {
+                exp = exp.saturating_sub(4);
+            }
+        } else {
+            sticky |= d != 0;
+            if !is_frac {
+                exp = exp.saturating_add(4);
+            }
+        }
+    }
+    // Mantissa has more than 124 bits so its lowest bit is far below f64
+    // precision and only breaks rounding ties.
+    if sticky {
+        m |= 1;
+    }
+
+    // Mantissa is below 2^128 so past these bounds the result is `inf` or `0`.
+    let mut exp = exp.clamp(-1300, 1300) as i32;
+    let mut n = m as f64;
+    // Scale in steps so 2^exp itself doesn't overflow.
+    while exp != 0 {
+        let step = exp.clamp(-1000, 1000);
+        n *= 2f64.powi(step);
+        exp -= step;
+    }
+
+    Some(n)
+}
+
+/// Parses exponent of a number literal, saturating on overflow.
+fn parse_exponent(exp: &str) -> Option<i64> {
+    let (neg, digits) = match exp.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, exp.strip_prefix('+').unwrap_or(exp)),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let n = digits.chars().try_fold(0i64, |n, c| {
+        Some(n.saturating_mul(10).saturating_add(c.to_digit(10)? as i64))
+    })?;
+
+    Some(if neg { -n } else { n })
+}
+
 /// UnionType define a union of types. All types that can be assigned to one of
 /// union's variant type can be assigned to the union.
 #[derive(Debug, Clone)]
@@ -172,7 +401,7 @@ impl fmt::Display for UnionType {
         )?;
 
         if f.alternate() {
-            f.write_str("(")?;
+            f.write_str(")")?;
         }
 
         Ok(())
@@ -180,9 +409,43 @@ impl fmt::Display for UnionType {
 }
 
 impl UnionType {
+    fn normalize(&self) -> Type {
+        let mut variants: Vec<Type> = Vec::new();
+
+        for v in self
+            .variants
+            .iter()
+            .map(Type::normalize)
+            .flat_map(|v| match v {
+                Type::Union(u) => u.variants,
+                v => vec![v],
+            })
+        {
+            match v {
+                Type::Never(_) => continue,
+                Type::Any(_) => return v,
+                _ => {}
+            }
+
+            // Variant is redundant.
+            if variants.iter().any(|kept| kept.can_assign(&v)) {
+                continue;
+            }
+
+            variants.retain(|kept| !v.can_assign(kept));
+            variants.push(v);
+        }
+
+        match variants.len() {
+            0 => Type::Never(NeverType),
+            1 => variants.remove(0),
+            _ => Type::Union(UnionType::from(variants)),
+        }
+    }
+
     fn can_assign(&self, rhs: &Type) -> bool {
         match rhs {
-            Type::Primitive(_) | Type::Literal(_) => {
+            Type::Primitive(_) | Type::Literal(_) | Type::Array(_) | Type::Tuple(_) => {
                 for v in self.variants.iter() {
                     if v.can_assign(rhs) {
                         return true;
@@ -190,8 +453,7 @@ impl UnionType {
                 }
                 false
             }
-            Type::Union(UnionType { variants })
-            | Type::Intersection(IntersectionType { variants }) => {
+            Type::Union(UnionType { variants }) => {
                 for v in variants.iter() {
                     if !self.can_assign(v) {
                         return false;
@@ -199,6 +461,12 @@ impl UnionType {
                 }
                 true
             }
+            // Intersection can be assigned to one of union's variant or one of
+            // its variants can be assigned to the union.
+            Type::Intersection(IntersectionType { variants }) => {
+                self.variants.iter().any(|v| v.can_assign(rhs))
+                    || variants.iter().any(|v| self.can_assign(v))
+            }
             _ => false,
         }
     }
@@ -255,9 +523,16 @@ impl fmt::Display for IntersectionType {
 }
 
 impl IntersectionType {
+    fn normalize(&self) -> Type {
+        self.variants
+            .iter()
+            .map(Type::normalize)
+            .fold(Type::Any(AnyType), intersect)
+    }
+
     fn can_assign(&self, rhs: &Type) -> bool {
         match rhs {
-            Type::Primitive(_) | Type::Literal(_) => {
+            Type::Primitive(_) | Type::Literal(_) | Type::Array(_) | Type::Tuple(_) => {
                 for v in self.variants.iter() {
                     if !v.can_assign(rhs) {
                         return false;
@@ -265,8 +540,7 @@ impl IntersectionType {
                 }
                 true
             }
-            Type::Union(UnionType { variants })
-            | Type::Intersection(IntersectionType { variants }) => {
+            Type::Union(UnionType { variants }) => {
                 for v in variants.iter() {
                     if !self.can_assign(v) {
                         return false;
@@ -274,11 +548,135 @@ impl IntersectionType {
                 }
                 true
             }
+            // Intersection must be assignable to all variants.
+            Type::Intersection(_) => self.variants.iter().all(|v| v.can_assign(rhs)),
             _ => false,
         }
     }
 }
 
+/// Returns intersection of two normalized types.
+fn intersect(lhs: Type, rhs: Type) -> Type {
+    match (lhs, rhs) {
+        (Type::Never(_), _) | (_, Type::Never(_)) => Type::Never(NeverType),
+        (Type::Any(_), t) | (t, Type::Any(_)) => t,
+        // Intersection distributes over union.
+        (Type::Union(u), t) | (t, Type::Union(u)) => UnionType::from(
+            u.variants
+                .into_iter()
+                .map(|v| intersect(v, t.clone()))
+                .collect::<Vec<_>>(),
+        )
+        .normalize(),
+        (lhs, rhs) => {
+            let mut variants: Vec<Type> = Vec::new();
+
+            for v in [lhs, rhs].into_iter().flat_map(|t| match t {
+                Type::Intersection(i) => i.variants,
+                t => vec![t],
+            }) {
+                // Variant is redundant.
+                if variants.iter().any(|kept| v.can_assign(kept)) {
+                    continue;
+                }
+
+                if variants.iter().any(|kept| kept.is_disjoint(&v)) {
+                    return Type::Never(NeverType);
+                }
+
+                variants.retain(|kept| !kept.can_assign(&v));
+                variants.push(v);
+            }
+
+            match variants.len() {
+                1 => variants.remove(0),
+                _ => Type::Intersection(IntersectionType::from(variants)),
+            }
+        }
+    }
+}
+
+/// ArrayType define a Lua sequence whose elements are all of the same type.
+/// Arrays are covariant, `T[]` can be assigned to `U[]` if `T` can be assigned
+/// to `U`.
+#[derive(Debug, Clone)]
+pub struct ArrayType {
+    element: Box<Type>,
+}
+
+impl From<ArrayType> for Type {
+    fn from(value: ArrayType) -> Self {
+        Type::Array(value)
+    }
+}
+
+impl From<Type> for ArrayType {
+    fn from(value: Type) -> Self {
+        Self {
+            element: Box::new(value),
+        }
+    }
+}
+
+impl fmt::Display for ArrayType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}[]", self.element)
+    }
+}
+
+/// TupleType define a Lua sequence of fixed length where each element has its
+/// own type.
+#[derive(Debug, Clone)]
+pub struct TupleType {
+    elements: Vec<Type>,
+}
+
+impl From<TupleType> for Type {
+    fn from(value: TupleType) -> Self {
+        Type::Tuple(value)
+    }
+}
+
+impl From<Vec<Type>> for TupleType {
+    fn from(value: Vec<Type>) -> Self {
+        Self { elements: value }
+    }
+}
+
+impl fmt::Display for TupleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+
+        f.write_str(
+            &self
+                .elements
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        )?;
+
+        f.write_str("]")
+    }
+}
+
+impl TupleType {
+    /// Returns type of element at the given index. As in Lua, indices starts
+    /// at 1.
+    pub fn field(&self, index: usize) -> Option<&Type> {
+        index.checked_sub(1).and_then(|i| self.elements.get(i))
+    }
+
+    fn can_assign(&self, rhs: &TupleType) -> bool {
+        self.elements.len() == rhs.elements.len()
+            && self
+                .elements
+                .iter()
+                .zip(rhs.elements.iter())
+                .all(|(lhs, rhs)| lhs.can_assign(rhs))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,7 +770,9 @@ mod tests {
             assert!(!lhs.can_assign(&never));
             assert!(!lhs.can_assign(&union_num_str));
             assert!(!lhs.can_assign(&union_num_nil));
-            assert!(!lhs.can_assign(&inter_union_num_str_union_num_nil));
+
+            // Intersection is normalized to number.
+            assert_eq!(lhs.can_assign(&inter_union_num_str_union_num_nil), i == 2);
         }
     }
 
@@ -403,7 +803,10 @@ mod tests {
         assert!(union_num_str.can_assign(&union_num_str));
 
         assert!(!union_num_str.can_assign(&union_num_nil));
-        assert!(!union_num_str.can_assign(&inter_union_num_str_union_num_nil));
+
+        // Intersection is normalized to number.
+        assert!(union_num_str.can_assign(&inter_union_num_str_union_num_nil));
+        assert!(union_num_nil.can_assign(&inter_union_num_str_union_num_nil));
     }
 
     #[test]
@@ -434,7 +837,451 @@ mod tests {
         // Only number can be assigned as it is present in both union.
         assert!(inter_union_num_str_union_num_nil.can_assign(&number));
 
-        // This doesn't work unless we normalize the intersection.
-        assert!(!inter_union_num_str_union_num_nil.can_assign(&inter_union_num_str_union_num_nil));
+        // Intersection is normalized so it can be assigned to itself.
+        assert!(inter_union_num_str_union_num_nil.can_assign(&inter_union_num_str_union_num_nil));
+
+        // Intersections that can't be normalized are also assignable to
+        // themselves.
+        let array_num = Type::Array(ArrayType::from(number.clone()));
+        let array_str = Type::Array(ArrayType::from(string.clone()));
+        let inter_array_num_array_str = Type::Intersection(IntersectionType::from(vec![
+            array_num.clone(),
+            array_str.clone(),
+        ]));
+        let union_inter_array_num_array_str_num = Type::Union(UnionType::from(vec![
+            inter_array_num_array_str.clone(),
+            number.clone(),
+        ]));
+
+        assert!(inter_array_num_array_str.can_assign(&inter_array_num_array_str));
+        assert!(
+            union_inter_array_num_array_str_num.can_assign(&union_inter_array_num_array_str_num)
+        );
+        assert!(union_inter_array_num_array_str_num.can_assign(&inter_array_num_array_str));
+        assert!(array_num.can_assign(&inter_array_num_array_str));
+        assert!(array_str.can_assign(&inter_array_num_array_str));
+        assert!(!inter_array_num_array_str.can_assign(&array_num));
+        assert!(!number.can_assign(&inter_array_num_array_str));
+    }
+
+    #[test]
+    fn normalize() {
+        let nil = Type::Primitive(PrimitiveType::Nil);
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+
+        let any = Type::Any(AnyType);
+        let never = Type::Never(NeverType);
+
+        let union_num_str = Type::Union(UnionType::from(vec![number.clone(), string.clone()]));
+        let union_num_nil = Type::Union(UnionType::from(vec![number.clone(), nil.clone()]));
+        let array_num = Type::Array(ArrayType::from(number.clone()));
+        let array_str = Type::Array(ArrayType::from(string.clone()));
+
+        for (t, expected) in [
+            (
+                Type::Intersection(IntersectionType::from(vec![
+                    union_num_str.clone(),
+                    union_num_nil.clone(),
+                ])),
+                "number",
+            ),
+            (
+                Type::Intersection(IntersectionType::from(vec![
+                    union_num_str.clone(),
+                    union_num_str.clone(),
+                ])),
+                "number | string",
+            ),
+            (
+                Type::Intersection(IntersectionType::from(vec![number.clone(), string.clone()])),
+                "never",
+            ),
+            (
+                Type::Intersection(IntersectionType::from(vec![number.clone(), any.clone()])),
+                "number",
+            ),
+            (
+                Type::Intersection(IntersectionType::from(vec![
+                    number.clone(),
+                    number_literal("1"),
+                ])),
+                "1",
+            ),
+            (
+                Type::Intersection(IntersectionType::from(vec![
+                    number_literal("1"),
+                    number_literal("2"),
+                ])),
+                "never",
+            ),
+            (
+                Type::Intersection(IntersectionType::from(vec![
+                    array_num.clone(),
+                    array_str.clone(),
+                ])),
+                "number[] & string[]",
+            ),
+            (
+                Type::Union(UnionType::from(vec![
+                    number.clone(),
+                    never.clone(),
+                    number_literal("1"),
+                    union_num_nil.clone(),
+                ])),
+                "number | nil",
+            ),
+            (
+                Type::Union(UnionType::from(vec![number.clone(), any.clone()])),
+                "any",
+            ),
+            (Type::Union(UnionType::from(vec![])), "never"),
+            (
+                Type::Array(ArrayType::from(Type::Intersection(IntersectionType::from(
+                    vec![union_num_str.clone(), union_num_nil.clone()],
+                )))),
+                "number[]",
+            ),
+        ] {
+            assert_eq!(t.normalize().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn array_can_assign() {
+        let nil = Type::Primitive(PrimitiveType::Nil);
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+
+        let any = Type::Any(AnyType);
+        let never = Type::Never(NeverType);
+
+        let union_num_str = Type::Union(UnionType::from(vec![number.clone(), string.clone()]));
+        let array_num = Type::Array(ArrayType::from(number.clone()));
+        let array_str = Type::Array(ArrayType::from(string.clone()));
+        let array_union_num_str = Type::Array(ArrayType::from(union_num_str.clone()));
+        let tuple_num_str = Type::Tuple(TupleType::from(vec![number.clone(), string.clone()]));
+        let tuple_num_num = Type::Tuple(TupleType::from(vec![number.clone(), number.clone()]));
+        let union_array_num_nil =
+            Type::Union(UnionType::from(vec![array_num.clone(), nil.clone()]));
+
+        assert!(!array_num.can_assign(&nil));
+        assert!(!array_num.can_assign(&number));
+        assert!(!array_num.can_assign(&any));
+        assert!(!array_num.can_assign(&never));
+
+        assert!(array_num.can_assign(&array_num));
+        assert!(!array_num.can_assign(&array_str));
+
+        // Arrays are covariant.
+        assert!(array_union_num_str.can_assign(&array_num));
+        assert!(array_union_num_str.can_assign(&array_str));
+        assert!(!array_num.can_assign(&array_union_num_str));
+
+        // Tuples can be assigned to arrays if all elements can.
+        assert!(array_num.can_assign(&tuple_num_num));
+        assert!(!array_num.can_assign(&tuple_num_str));
+        assert!(array_union_num_str.can_assign(&tuple_num_str));
+
+        assert!(union_array_num_nil.can_assign(&array_num));
+        assert!(union_array_num_nil.can_assign(&nil));
+        assert!(!union_array_num_nil.can_assign(&array_str));
+    }
+
+    #[test]
+    fn tuple_can_assign() {
+        let nil = Type::Primitive(PrimitiveType::Nil);
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+
+        let any = Type::Any(AnyType);
+        let never = Type::Never(NeverType);
+
+        let union_num_str = Type::Union(UnionType::from(vec![number.clone(), string.clone()]));
+        let array_num = Type::Array(ArrayType::from(number.clone()));
+        let tuple_num_str = Type::Tuple(TupleType::from(vec![number.clone(), string.clone()]));
+        let tuple_str_num = Type::Tuple(TupleType::from(vec![string.clone(), number.clone()]));
+        let tuple_num_num = Type::Tuple(TupleType::from(vec![number.clone(), number.clone()]));
+        let tuple_num = Type::Tuple(TupleType::from(vec![number.clone()]));
+        let tuple_union_num_str_str =
+            Type::Tuple(TupleType::from(vec![union_num_str.clone(), string.clone()]));
+
+        assert!(!tuple_num_str.can_assign(&nil));
+        assert!(!tuple_num_str.can_assign(&number));
+        assert!(!tuple_num_str.can_assign(&any));
+        assert!(!tuple_num_str.can_assign(&never));
+
+        assert!(tuple_num_str.can_assign(&tuple_num_str));
+        assert!(!tuple_num_str.can_assign(&tuple_str_num));
+        assert!(!tuple_num_str.can_assign(&tuple_num_num));
+
+        // Length must match.
+        assert!(!tuple_num_str.can_assign(&tuple_num));
+        assert!(!tuple_num.can_assign(&tuple_num_str));
+
+        // Arrays can't be assigned to tuples as their length is unknown.
+        assert!(!tuple_num.can_assign(&array_num));
+
+        // Elements are assigned positionally.
+        assert!(tuple_union_num_str_str.can_assign(&tuple_num_str));
+        assert!(!tuple_union_num_str_str.can_assign(&tuple_str_num));
+        assert!(!tuple_num_str.can_assign(&tuple_union_num_str_str));
+    }
+
+    #[test]
+    fn tuple_field() {
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+        let tuple_num_str = TupleType::from(vec![number.clone(), string.clone()]);
+
+        assert!(tuple_num_str.field(0).is_none());
+        assert!(number.can_assign(tuple_num_str.field(1).unwrap()));
+        assert!(string.can_assign(tuple_num_str.field(2).unwrap()));
+        assert!(tuple_num_str.field(3).is_none());
+    }
+
+    #[test]
+    fn array_and_tuple_display() {
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+        let union_num_str = Type::Union(UnionType::from(vec![number.clone(), string.clone()]));
+
+        assert_eq!(
+            Type::Array(ArrayType::from(number.clone())).to_string(),
+            "number[]"
+        );
+        assert_eq!(
+            Type::Array(ArrayType::from(union_num_str.clone())).to_string(),
+            "(number | string)[]"
+        );
+        assert_eq!(
+            Type::Tuple(TupleType::from(vec![number, union_num_str])).to_string(),
+            "[number, number | string]"
+        );
+    }
+
+    fn number_literal(lit: &str) -> Type {
+        Type::Literal(LiteralType {
+            lit: lit.to_owned(),
+            primitive: PrimitiveType::Number,
+        })
+    }
+
+    #[test]
+    fn literal_can_assign() {
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+        let str_one = Type::Literal(LiteralType {
+            lit: "1".to_owned(),
+            primitive: PrimitiveType::String,
+        });
+        let str_one_float = Type::Literal(LiteralType {
+            lit: "1.0".to_owned(),
+            primitive: PrimitiveType::String,
+        });
+
+        assert!(number.can_assign(&number_literal("1")));
+        assert!(!number_literal("1").can_assign(&number));
+        assert!(string.can_assign(&str_one));
+        assert!(!number.can_assign(&str_one));
+
+        // Literals of different primitive types are distinct.
+        assert!(!number_literal("1").can_assign(&str_one));
+        assert!(!str_one.can_assign(&number_literal("1")));
+
+        // String literals are compared textually.
+        assert!(str_one.can_assign(&str_one));
+        assert!(!str_one.can_assign(&str_one_float));
+    }
+
+    #[test]
+    fn number_literal_can_assign_equivalent_forms() {
+        for (lhs, rhs) in [
+            ("1", "1.0"),
+            ("1", "1.00"),
+            ("1.0", "1."),
+            ("0.5", ".5"),
+            ("100", "1e2"),
+            ("100", "1E+2"),
+            ("0.01", "1e-2"),
+            ("255", "0xff"),
+            ("255", "0XFF"),
+            ("16", "0x1p4"),
+            ("24", "0x1.8P+4"),
+            ("0.5", "0x.8"),
+            ("0.25", "0x1p-2"),
+            ("18446744073709551616", "0x10000000000000000"),
+            // 2^60 + 129 rounds to 2^60 + 256.
+            ("1152921504606847232", "0x1000000000000081"),
+            // Ties are rounded to even unless digits past the mantissa aren't
+            // zeros.
+            (
+                "0x1000000000000000p88",
+                "0x10000000000000800000000000000000000000",
+            ),
+            (
+                "0x1000000000000100p88",
+                "0x10000000000000800000000000000000000001",
+            ),
+        ] {
+            assert!(number_literal(lhs).can_assign(&number_literal(rhs)));
+            assert!(number_literal(rhs).can_assign(&number_literal(lhs)));
+        }
+
+        for (lhs, rhs) in [
+            ("1", "2"),
+            ("1.5", "1"),
+            ("0x10", "10"),
+            ("1e2", "12"),
+            ("0x1p4", "0x1p3"),
+            ("1152921504606846976", "0x1000000000000081"),
+        ] {
+            assert!(!number_literal(lhs).can_assign(&number_literal(rhs)));
+            assert!(!number_literal(rhs).can_assign(&number_literal(lhs)));
+        }
+    }
+
+    #[test]
+    fn number_literal_can_assign_itself() {
+        let huge_hex = format!("0x{}p-1100", "f".repeat(300));
+
+        for lit in [
+            "1",
+            "0x1000000000000081",
+            "0x1.8p4",
+            &huge_hex,
+            "0x1p99999999999999999999",
+            "1e400",
+            "nan",
+            "inf",
+            "+1",
+        ] {
+            assert!(number_literal(lit).can_assign(&number_literal(lit)));
+        }
+    }
+
+    #[test]
+    fn parse_number_rejects_non_lua_literals() {
+        for lit in [
+            "nan", "NaN", "inf", "infinity", "+1", "-1", "", ".", "1e", "1e+", "1.2.3", "0x",
+            "0x.", "0xp1", "0x1p", "0x1g", "1e400", "0x1p1024",
+        ] {
+            assert!(parse_number(lit).is_none(), "{lit}");
+        }
+
+        assert_eq!(
+            parse_number(&format!("0x{}p-1100", "f".repeat(300))),
+            Some(2f64.powi(100))
+        );
+    }
+
+    #[test]
+    fn lt_le() {
+        let nil = Type::Primitive(PrimitiveType::Nil);
+        let boolean = Type::Primitive(PrimitiveType::Boolean);
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+
+        let any = Type::Any(AnyType);
+        let never = Type::Never(NeverType);
+
+        let union_num_str = Type::Union(UnionType::from(vec![number.clone(), string.clone()]));
+        let union_num_nil = Type::Union(UnionType::from(vec![number.clone(), nil.clone()]));
+        let inter_num_union_num_str = Type::Intersection(IntersectionType::from(vec![
+            number.clone(),
+            union_num_str.clone(),
+        ]));
+        let inter_union_num_str_union_num_nil = Type::Intersection(IntersectionType::from(vec![
+            union_num_str.clone(),
+            union_num_nil.clone(),
+        ]));
+        let union_num_never = Type::Union(UnionType::from(vec![number.clone(), never.clone()]));
+        let union_any_num = Type::Union(UnionType::from(vec![any.clone(), number.clone()]));
+        let array_num = Type::Array(ArrayType::from(number.clone()));
+
+        for op in [Type::lt, Type::le] {
+            for (lhs, rhs) in [
+                (&number, &number),
+                (&string, &string),
+                (&number, &number_literal("1")),
+                (&number_literal("1"), &number_literal("2")),
+                (&any, &number),
+                (&string, &any),
+                (&any, &any),
+                (&any, &union_num_str),
+                (&any, &number_literal("1")),
+                (&inter_num_union_num_str, &number),
+                (&inter_union_num_str_union_num_nil, &number),
+                // Unions are normalized to `number` and `any`.
+                (&union_num_never, &number),
+                (&union_any_num, &string),
+            ] {
+                assert!(boolean.can_assign(&op(lhs, rhs).unwrap()));
+                assert!(boolean.can_assign(&op(rhs, lhs).unwrap()));
+            }
+
+            for (lhs, rhs) in [
+                (&number, &string),
+                (&nil, &nil),
+                (&boolean, &boolean),
+                (&array_num, &array_num),
+                (&union_num_str, &number),
+                (&union_num_nil, &number),
+                (&boolean, &any),
+                (&nil, &any),
+                (&array_num, &any),
+                (&union_num_nil, &any),
+                (&never, &number),
+                (&never, &any),
+            ] {
+                assert!(op(lhs, rhs).is_none());
+                assert!(op(rhs, lhs).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn eq() {
+        let nil = Type::Primitive(PrimitiveType::Nil);
+        let boolean = Type::Primitive(PrimitiveType::Boolean);
+        let number = Type::Primitive(PrimitiveType::Number);
+        let string = Type::Primitive(PrimitiveType::String);
+
+        let any = Type::Any(AnyType);
+        let never = Type::Never(NeverType);
+
+        let union_num_str = Type::Union(UnionType::from(vec![number.clone(), string.clone()]));
+        let union_num_nil = Type::Union(UnionType::from(vec![number.clone(), nil.clone()]));
+        let array_num = Type::Array(ArrayType::from(number.clone()));
+
+        for (lhs, rhs) in [
+            (&any, &any),
+            (&any, &string),
+            (&nil, &nil),
+            (&boolean, &boolean),
+            (&number, &number),
+            (&number, &number_literal("1")),
+            (&union_num_nil, &nil),
+            (&union_num_nil, &number),
+            // Both unions may hold a number.
+            (&union_num_str, &union_num_nil),
+            (&array_num, &array_num),
+        ] {
+            assert!(boolean.can_assign(&lhs.eq(rhs).unwrap()));
+            assert!(boolean.can_assign(&rhs.eq(lhs).unwrap()));
+        }
+
+        for (lhs, rhs) in [
+            (&number, &string),
+            (&nil, &boolean),
+            (&union_num_nil, &string),
+            (&number_literal("1"), &number_literal("2")),
+            (&array_num, &number),
+            (&never, &number),
+            (&never, &any),
+        ] {
+            assert!(lhs.eq(rhs).is_none());
+            assert!(rhs.eq(lhs).is_none());
+        }
     }
 }